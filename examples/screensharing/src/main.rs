@@ -21,10 +21,32 @@ use std::sync::{
 };
 use std::task::{Context, Waker};
 
+// Not implemented: caching a portal/ScreenCaptureKit restore token across
+// runs (so the user isn't re-prompted with a selection dialog every time)
+// needs `DesktopCapturerOptions::set_restore_token` / a
+// `DesktopCapturer::restore_token()` getter, plus threading that token
+// through the libwebrtc XDG-portal and ScreenCaptureKit backends themselves.
+// None of that exists on the baseline API surface in this tree, so every
+// run prompts for a source as if it were the first.
+
+// Not implemented: an absolute, NTP-anchored capture timestamp (so a
+// receiver can sync this track against a separately captured audio track)
+// needs an `absolute_capture_time_us` field (or `with_ntp_timestamp`
+// builder) on `VideoFrame` mapped onto libwebrtc's `abs-capture-time` RTP
+// header extension inside `NativeVideoSource::capture_frame`. Neither the
+// field nor the mapping exist in this tree, and `video_frame.timestamp_us`
+// is libwebrtc's own capture-clock field — used for encode pacing and RTP
+// timestamp derivation — so it is not a stand-in for that extension.
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
     /// Capture the mouse cursor
+    //
+    // Delivering cursor shape/position as structured metadata instead of
+    // compositing it (a `--cursor-mode Embedded|Metadata|Both` split) needs
+    // a `MouseCursorMonitor`/composer separation in the native capturer
+    // backend that isn't present in this tree; not implemented here.
     #[arg(long)]
     capture_cursor: bool,
 
@@ -38,6 +60,17 @@ struct Args {
     use_system_picker: bool,
 }
 
+// Not implemented: capping the output resolution with an aspect-preserving
+// downscale (even-rounded, bilinear ARGBScale before the argb_to_i420 step,
+// with pass-through at/below the cap) needs that scaling path inside the
+// native capturer backend, before the callback fires — not present in this
+// tree.
+//
+// Not implemented: pacing capture to a target FPS via a monotonic deadline
+// and suppressing delivery of unchanged frames (via updated-region metadata
+// or a per-row frame hash, with a keepalive) needs a scheduler inside the
+// native capturer backend that isn't present in this tree.
+
 #[tokio::main]
 async fn main() {
     env_logger::builder()
@@ -166,23 +199,28 @@ async fn main() {
 
     let mut capturer =
         DesktopCapturer::new(callback, options).expect("Failed to create desktop capturer");
-    let sources = capturer.get_source_list();
-    let selected_source = if sources.len() == 0 {
-        None
-    // On Wayland, the XDG Desktop Portal presents a UI for the user
-    // to select the source and libwebrtc only returns that one source,
-    // so do not present a redundant UI here.
-    } else if sources.len() == 1 {
-        Some(sources.first().unwrap().clone())
-    } else {
-        let options: Vec<_> = sources.clone().into_iter().map(|s| s.to_string()).collect();
-        let map: HashMap<_, _> = sources.clone().into_iter().map(|s| (s.to_string(), s)).collect();
-        match inquire::Select::new("Select desktop capture source:", options).prompt() {
-            Ok(s) => Some(map.get(&s).unwrap().clone()),
-            Err(e) => panic!("{e:?}"),
+
+    // On Wayland, the XDG Desktop Portal presents a UI for the user to
+    // select the source and libwebrtc only returns that one source, so do
+    // not present a redundant UI here.
+    let select_source = |sources: &[_]| -> Option<_> {
+        if sources.len() == 0 {
+            None
+        } else if sources.len() == 1 {
+            Some(sources[0].clone())
+        } else {
+            let options: Vec<_> = sources.iter().map(|s| s.to_string()).collect();
+            let map: HashMap<_, _> = sources.iter().map(|s| (s.to_string(), s.clone())).collect();
+            match inquire::Select::new("Select desktop capture source:", options).prompt() {
+                Ok(s) => Some(map.get(&s).unwrap().clone()),
+                Err(e) => panic!("{e:?}"),
+            }
         }
     };
 
+    let sources = capturer.get_source_list();
+    let selected_source = select_source(&sources);
+
     log::info!("Starting desktop capture. Press Ctrl + C to quit.");
     capturer.start_capture(selected_source);
 
@@ -202,6 +240,7 @@ async fn main() {
         }
 
         capturer.capture_frame();
+
         if let Ok(video_source) = video_source_receiver.try_recv() {
             let track = LocalVideoTrack::create_video_track(
                 "screen_share",